@@ -2,12 +2,290 @@ use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// These five modules hold logic the build script needs, mounted here via
+// `#[path]` instead of being written only in `src/`, so the first four are
+// also exercised by plain `cargo test` and not just by a `cargo build` run of
+// this file; `grammar_wasm_compile`'s own test only builds under
+// `wasm-grammar-libs` and is `#[ignore]`d by default.
+#[path = "src/build_config.rs"]
+mod config;
+use config::{GrammarSource, LanguagesConfig};
+
+#[path = "src/file_extensions.rs"]
+mod file_extensions;
+use file_extensions::default_file_extensions;
+
+#[path = "src/symbol_name.rs"]
+mod symbol_name;
+
+#[path = "src/artifact_freshness.rs"]
+mod artifact_freshness;
+use artifact_freshness::artifact_is_up_to_date;
+
+#[path = "src/grammar_wasm_compile.rs"]
+mod grammar_wasm_compile;
+
+// Matches `dynamic::load_grammar_dynamic`'s `<runtime_dir>/grammars/<name>.<ext>`
+// layout, so a `dynamic-grammar-libs` build's output can be copied straight
+// into an embedder's runtime directory.
+#[cfg(target_os = "macos")]
+const DYNAMIC_LIB_EXTENSION: &str = "dylib";
+#[cfg(target_os = "windows")]
+const DYNAMIC_LIB_EXTENSION: &str = "dll";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const DYNAMIC_LIB_EXTENSION: &str = "so";
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct Grammar {
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     symbol_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    file_extensions: Vec<String>,
+}
+
+/// Shallow-fetch `rev` of `git` into `<out_dir>/grammars-git/<name>` and
+/// return the checked-out directory.
+fn fetch_git_grammar(out_dir: &Path, name: &str, git: &str, rev: &str) -> PathBuf {
+    let checkout_dir = out_dir.join("grammars-git").join(name);
+    let rev_marker = checkout_dir.join(".checked-out-rev");
+    let wanted = format!("{git}@{rev}");
+
+    // Skip the network entirely once we've already fetched this exact
+    // (remote, rev) pair - editing either field in `languages.toml`
+    // invalidates the marker and forces a refetch.
+    if fs::read_to_string(&rev_marker).ok().as_deref() == Some(wanted.as_str()) {
+        eprintln!("Validation: {name} already at {wanted}, skipping fetch");
+        return checkout_dir;
+    }
+
+    fs::create_dir_all(&checkout_dir).expect("failed to create git checkout dir");
+
+    if !checkout_dir.join(".git").exists() {
+        run_git(&checkout_dir, &["init", "-q"]);
+        run_git(&checkout_dir, &["remote", "add", "origin", git]);
+    } else {
+        // `git` may have changed since the last build; keep `origin` in
+        // sync instead of silently fetching from the stale remote.
+        run_git(&checkout_dir, &["remote", "set-url", "origin", git]);
+    }
+    run_git(&checkout_dir, &["fetch", "--depth", "1", "origin", rev]);
+    run_git(&checkout_dir, &["checkout", "-q", "FETCH_HEAD"]);
+    fs::write(&rev_marker, &wanted).expect("failed to write rev marker");
+
+    checkout_dir
+}
+
+fn run_git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run `git {}`: {e}", args.join(" ")));
+    assert!(status.success(), "`git {}` failed in {}", args.join(" "), dir.display());
+}
+
+/// Resolve a grammar entry's source to the directory containing its
+/// `src/parser.c`.
+fn resolve_grammar_source_dir(out_dir: &Path, name: &str, source: &GrammarSource) -> PathBuf {
+    match source {
+        GrammarSource::Local { path } => PathBuf::from(path),
+        GrammarSource::Git { git, rev, subpath } => {
+            let checkout = fetch_git_grammar(out_dir, name, git, rev);
+            match subpath {
+                Some(subpath) => checkout.join(subpath),
+                None => checkout,
+            }
+        }
+    }
+}
+
+/// Whether we're building for a `wasm32-*` target, in which case grammars
+/// are emitted as `.wasm` modules instead of native static archives, and
+/// native C++ stdlib linking doesn't apply.
+fn is_wasm32_target() -> bool {
+    env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32")
+}
+
+/// `<out_dir>/grammars/<name>.wasm`, matching `dynamic::grammar_library_path`'s
+/// `grammars/` subdir convention and what `wasm::grammar_wasm_path` expects
+/// at runtime.
+fn grammar_wasm_artifact_path(out_dir: &Path, name: &str) -> PathBuf {
+    out_dir.join("grammars").join(format!("{name}.wasm"))
+}
+
+fn grammar_artifact_path(out_dir: &Path, name: &str) -> PathBuf {
+    out_dir.join(format!("libtree-sitter-{name}.a"))
+}
+
+/// Compile `<grammar_dir>/src/parser.c` (plus `scanner.c`/`scanner.cc` if
+/// present) into a static archive under `out_dir`.
+fn compile_grammar_sources(grammar_dir: &Path, name: &str, out_dir: &Path) {
+    let src_dir = grammar_dir.join("src");
+    let (parser_c, scanner) = grammar_wasm_compile::find_grammar_sources(&src_dir);
+
+    let mut sources = vec![parser_c.clone()];
+    sources.extend(scanner.iter().map(|(path, _)| path.clone()));
+    for source in &sources {
+        println!("cargo:rerun-if-changed={}", source.display());
+    }
+
+    let archive_path = grammar_artifact_path(out_dir, name);
+    if artifact_is_up_to_date(&sources, &archive_path) {
+        eprintln!("Validation: {name} is up to date, skipping recompile");
+        return;
+    }
+
+    let mut build = cc::Build::new();
+    build.include(&src_dir).file(&parser_c);
+    if let Some((path, is_cpp)) = &scanner {
+        build.cpp(*is_cpp).file(path);
+    }
+
+    build.out_dir(out_dir).compile(&format!("tree-sitter-{name}"));
+}
+
+/// Compile a grammar into a relocatable `<out_dir>/grammars/<name>.wasm`
+/// side module via emscripten, for `wasm32` targets. Loaded at runtime
+/// through `tree_sitter::WasmStore` by `wasm::load_grammar_wasm` - there's
+/// nothing to link into the binary, same as `compile_grammar_shared_lib`.
+/// The actual `emcc` invocation lives in `grammar_wasm_compile` so a test
+/// there can exercise the exact same flags.
+fn compile_grammar_wasm(grammar_dir: &Path, name: &str, out_dir: &Path) {
+    let src_dir = grammar_dir.join("src");
+    let (parser_c, scanner) = grammar_wasm_compile::find_grammar_sources(&src_dir);
+
+    let mut sources = vec![parser_c.clone()];
+    sources.extend(scanner.iter().map(|(path, _)| path.clone()));
+    for source in &sources {
+        println!("cargo:rerun-if-changed={}", source.display());
+    }
+
+    let grammars_dir = out_dir.join("grammars");
+    fs::create_dir_all(&grammars_dir).expect("failed to create grammars output dir");
+    let wasm_path = grammar_wasm_artifact_path(out_dir, name);
+
+    if artifact_is_up_to_date(&sources, &wasm_path) {
+        eprintln!("Validation: {name} wasm module is up to date, skipping recompile");
+        return;
+    }
+
+    grammar_wasm_compile::compile_grammar_wasm_module(&src_dir, &wasm_path);
+}
+
+/// Compile a grammar into a `<out_dir>/grammars/<name>.<so|dylib|dll>`
+/// shared library instead of a static archive, for `dynamic-grammar-libs`
+/// builds where nothing should be linked into the binary. Embedders copy
+/// `OUT_DIR/grammars/` into the runtime directory `dynamic::load_grammar_dynamic`
+/// is pointed at.
+fn compile_grammar_shared_lib(grammar_dir: &Path, name: &str, out_dir: &Path) {
+    let src_dir = grammar_dir.join("src");
+    let (parser_c, scanner) = grammar_wasm_compile::find_grammar_sources(&src_dir);
+
+    let mut sources = vec![parser_c.clone()];
+    sources.extend(scanner.iter().map(|(path, _)| path.clone()));
+    for source in &sources {
+        println!("cargo:rerun-if-changed={}", source.display());
+    }
+
+    let grammars_dir = out_dir.join("grammars");
+    fs::create_dir_all(&grammars_dir).expect("failed to create grammars output dir");
+    let lib_path = grammars_dir.join(format!("{name}.{DYNAMIC_LIB_EXTENSION}"));
+
+    if artifact_is_up_to_date(&sources, &lib_path) {
+        eprintln!("Validation: {name} shared lib is up to date, skipping recompile");
+        return;
+    }
+
+    // `cc::Build::compile` only ever produces a static archive, so the
+    // shared library is linked by hand from the `Tool` it resolves (this
+    // still picks up the right compiler, include paths and CFLAGS).
+    let is_cpp = scanner.as_ref().is_some_and(|(_, is_cpp)| *is_cpp);
+    let mut build = cc::Build::new();
+    build.include(&src_dir).cpp(is_cpp);
+    let mut cmd = build.get_compiler().to_command();
+
+    cmd.arg(if cfg!(target_os = "macos") {
+        "-dynamiclib"
+    } else {
+        "-shared"
+    });
+    cmd.arg("-fPIC");
+    cmd.arg(&parser_c);
+    if let Some((path, _)) = &scanner {
+        cmd.arg(path);
+    }
+    cmd.arg("-I").arg(&src_dir);
+    cmd.arg("-o").arg(&lib_path);
+
+    let status = cmd
+        .status()
+        .unwrap_or_else(|e| panic!("failed to invoke compiler for {name}: {e}"));
+    assert!(status.success(), "failed to build shared library for {name}");
+}
+
+/// Whether to actually fetch/compile the grammars this build needs (either
+/// `languages.toml`'s git sources or the prebuilt `dist/` archive), gated
+/// behind an env var instead of running unconditionally. Without it, a
+/// plain `cargo build`/`check`/`test` - which only needs this crate's
+/// pure-logic unit tests (`symbol_name`, `file_extensions`,
+/// `artifact_freshness`, `build_config`) to link - would otherwise shell
+/// out to `git fetch` from GitHub before a single test runs, which fails
+/// outright in network-restricted environments. Set this when you actually
+/// want `cargo run`'s parsing smoke test to have real, loadable grammars.
+fn grammars_build_requested() -> bool {
+    println!("cargo:rerun-if-env-changed=BREEZE_BUILD_GRAMMARS");
+    env::var_os("BREEZE_BUILD_GRAMMARS").is_some()
+}
+
+/// Build every grammar selected by `languages.toml`, if one is present next
+/// to `build.rs`. Returns `None` when there's no config, so callers fall
+/// back to the fixed `dist/` layout.
+fn build_configured_grammars(out_dir: &Path) -> Option<Vec<Grammar>> {
+    let config_path = Path::new("languages.toml");
+    let config = LanguagesConfig::load(config_path)?;
+
+    let selected = config.selected_grammars();
+    eprintln!(
+        "Validation: languages.toml selected {} of {} grammar(s)",
+        selected.len(),
+        config.grammar.len()
+    );
+
+    // Mirrors the feature split from the dist-based path below: under
+    // `static-grammar-libs` (the default, and whenever both features are on)
+    // compile a static archive and link it in; under `dynamic-grammar-libs`
+    // only, compile a shared library and link nothing - it's `dlopen`ed at
+    // runtime instead. `wasm32` targets are a third case - a standalone
+    // `.wasm` module, read from disk at runtime by `wasm::load_grammar_wasm`
+    // rather than linked.
+    let link_statically = cfg!(feature = "static-grammar-libs");
+
+    let mut grammars = Vec::with_capacity(selected.len());
+    for entry in selected {
+        let source_dir = resolve_grammar_source_dir(out_dir, &entry.name, &entry.source);
+        if is_wasm32_target() {
+            compile_grammar_wasm(&source_dir, &entry.name, out_dir);
+        } else if link_statically {
+            compile_grammar_sources(&source_dir, &entry.name, out_dir);
+            println!("cargo:rustc-link-lib=static=tree-sitter-{}", entry.name);
+        } else {
+            compile_grammar_shared_lib(&source_dir, &entry.name, out_dir);
+        }
+        grammars.push(Grammar {
+            name: entry.name.clone(),
+            symbol_name: None,
+            file_extensions: default_file_extensions(&entry.name),
+        });
+    }
+    if link_statically && !is_wasm32_target() {
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+    }
+    println!("cargo:rerun-if-changed={}", config_path.display());
+
+    Some(grammars)
 }
 
 fn get_validation_library_path() -> Result<PathBuf, String> {
@@ -72,52 +350,87 @@ fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir);
 
-    // Get validation library path
-    let lib_path = get_validation_library_path()
-        .expect("Failed to find validation library");
+    // Under `static-grammar-libs` we link the prebuilt `dist/*.a` archive and
+    // emit `extern` declarations for its symbols. Under dynamic-only builds
+    // we skip linking entirely so the crate builds without that archive
+    // present; grammars are instead `dlopen`ed at runtime (see `dynamic.rs`).
+    let test_grammars = if !grammars_build_requested() {
+        eprintln!(
+            "Validation: BREEZE_BUILD_GRAMMARS not set, skipping languages.toml/dist grammar \
+             fetch and compile so this build stays offline; the generated bindings will cover \
+             zero grammars. Set BREEZE_BUILD_GRAMMARS=1 to build real ones for `cargo run`."
+        );
+        Vec::new()
+    } else if let Some(configured) = build_configured_grammars(out_path) {
+        configured
+    } else if cfg!(feature = "static-grammar-libs") {
+        let lib_path = get_validation_library_path()
+            .expect("Failed to find validation library");
 
-    eprintln!("Validation: Using library from {}", lib_path.display());
+        eprintln!("Validation: Using library from {}", lib_path.display());
 
-    // Link the library - this is the critical test!
-    let lib_name = lib_path.file_stem().unwrap().to_str().unwrap();
-    let lib_name = lib_name.strip_prefix("lib").unwrap_or(lib_name);
+        // Link the library - this is the critical test!
+        let lib_name = lib_path.file_stem().unwrap().to_str().unwrap();
+        let lib_name = lib_name.strip_prefix("lib").unwrap_or(lib_name);
 
-    println!("cargo:rustc-link-lib=static={}", lib_name);
-    println!(
-        "cargo:rustc-link-search=native={}",
-        lib_path.parent().unwrap().display()
-    );
+        println!("cargo:rustc-link-lib=static={}", lib_name);
+        println!(
+            "cargo:rustc-link-search=native={}",
+            lib_path.parent().unwrap().display()
+        );
 
-    // Always link C++ standard library as some grammars use C++ scanners
-    if cfg!(target_os = "macos") {
-        println!("cargo:rustc-link-lib=c++");
-    } else if cfg!(target_os = "linux") {
-        println!("cargo:rustc-link-lib=stdc++");
-    } else if cfg!(target_os = "windows") {
-        // For MinGW/GNU on Windows
-        println!("cargo:rustc-link-lib=stdc++");
-    }
+        // Link the C++ standard library as some grammars use C++ scanners.
+        // Doesn't apply on wasm32, where grammars are loaded at runtime
+        // instead of linked.
+        if is_wasm32_target() {
+            // nothing to link
+        } else if cfg!(target_os = "macos") {
+            println!("cargo:rustc-link-lib=c++");
+        } else if cfg!(target_os = "linux") {
+            println!("cargo:rustc-link-lib=stdc++");
+        } else if cfg!(target_os = "windows") {
+            // For MinGW/GNU on Windows
+            println!("cargo:rustc-link-lib=stdc++");
+        }
 
-    // Load metadata and generate bindings
-    let metadata_path = get_metadata_path(&lib_path);
-    if metadata_path.exists() {
+        let metadata_path = get_metadata_path(&lib_path);
+        if !metadata_path.exists() {
+            panic!("Validation: No grammar metadata found at {}", metadata_path.display());
+        }
         eprintln!("Validation: Found metadata at: {}", metadata_path.display());
-        let metadata_str = fs::read_to_string(&metadata_path)
-            .expect("Failed to read grammar metadata");
-        let grammars: Vec<Grammar> = serde_json::from_str(&metadata_str)
-            .expect("Failed to parse grammar metadata");
-        
+        let metadata_str =
+            fs::read_to_string(&metadata_path).expect("Failed to read grammar metadata");
+        let grammars: Vec<Grammar> =
+            serde_json::from_str(&metadata_str).expect("Failed to parse grammar metadata");
+
         // Only generate bindings for a small subset to speed up validation
-        let test_grammars: Vec<Grammar> = grammars
+        grammars
             .into_iter()
             .filter(|g| ["c", "python", "javascript", "rust", "go"].contains(&g.name.as_str()))
-            .collect();
-            
-        eprintln!("Validation: Testing {} grammars", test_grammars.len());
-        generate_bindings(out_path, &test_grammars);
+            .map(|mut g| {
+                if g.file_extensions.is_empty() {
+                    g.file_extensions = default_file_extensions(&g.name);
+                }
+                g
+            })
+            .collect()
     } else {
-        panic!("Validation: No grammar metadata found at {}", metadata_path.display());
-    }
+        eprintln!("Validation: static-grammar-libs disabled, skipping static link");
+        // Dynamic-only builds don't need the dist archive; the grammar list
+        // just has to agree with what `dynamic::load_grammar_dynamic` can
+        // find at runtime.
+        ["c", "python", "javascript", "rust", "go"]
+            .into_iter()
+            .map(|name| Grammar {
+                name: name.to_string(),
+                symbol_name: None,
+                file_extensions: default_file_extensions(name),
+            })
+            .collect()
+    };
+
+    eprintln!("Validation: Testing {} grammars", test_grammars.len());
+    generate_bindings(out_path, &test_grammars);
 }
 
 fn generate_bindings(out_path: &Path, compiled_grammars: &[Grammar]) {
@@ -126,19 +439,29 @@ fn generate_bindings(out_path: &Path, compiled_grammars: &[Grammar]) {
 
     bindings.push_str("// Auto-generated validation grammar bindings\n\n");
     bindings.push_str("use tree_sitter::Language;\n");
+
+    // The statically-linked symbols only exist (and only link) when
+    // `static-grammar-libs` is enabled, so everything that touches them is
+    // gated behind that feature. They also never exist on `wasm32` -
+    // `build_configured_grammars` always takes the `compile_grammar_wasm`
+    // path there, regardless of `static-grammar-libs`, so declaring these
+    // `extern "C"` symbols on that target would fail to link.
+    // `#[allow(unused_imports)]` covers the degenerate case of zero
+    // configured grammars (e.g. a `BREEZE_BUILD_GRAMMARS`-less build), where
+    // nothing below ends up naming `LanguageFn`.
+    bindings.push_str("#[cfg(all(feature = \"static-grammar-libs\", not(target_arch = \"wasm32\")))]\n");
+    bindings.push_str("#[allow(unused_imports)]\n");
     bindings.push_str("use tree_sitter_language::LanguageFn;\n\n");
 
+    bindings.push_str("#[cfg(all(feature = \"static-grammar-libs\", not(target_arch = \"wasm32\")))]\n");
+    bindings.push_str("mod static_grammars {\n");
+    bindings.push_str("    use super::*;\n\n");
+
     // Generate extern declarations
     for grammar in compiled_grammars {
-        let fn_name = if let Some(symbol) = &grammar.symbol_name {
-            symbol.clone()
-        } else if grammar.name == "csharp" {
-            "c_sharp".to_string()
-        } else {
-            grammar.name.replace("-", "_")
-        };
+        let fn_name = symbol_name_for(grammar);
         bindings.push_str(&format!(
-            "unsafe extern \"C\" {{ fn tree_sitter_{}() -> *const (); }}\n",
+            "    unsafe extern \"C\" {{ fn tree_sitter_{}() -> *const (); }}\n",
             fn_name
         ));
     }
@@ -147,44 +470,113 @@ fn generate_bindings(out_path: &Path, compiled_grammars: &[Grammar]) {
 
     // Generate LanguageFn constants
     for grammar in compiled_grammars {
-        let fn_name = if let Some(symbol) = &grammar.symbol_name {
-            symbol.clone()
-        } else if grammar.name == "csharp" {
-            "c_sharp".to_string()
-        } else {
-            grammar.name.replace("-", "_")
-        };
+        let fn_name = symbol_name_for(grammar);
         let const_name = grammar.name.to_uppercase();
         bindings.push_str(&format!(
-            "pub const {}_LANGUAGE: LanguageFn = unsafe {{ LanguageFn::from_raw(tree_sitter_{}) }};\n",
+            "    pub const {}_LANGUAGE: LanguageFn = unsafe {{ LanguageFn::from_raw(tree_sitter_{}) }};\n",
             const_name, fn_name
         ));
     }
 
     bindings.push('\n');
-    bindings.push_str("pub fn load_grammar(name: &str) -> Option<Language> {\n");
-    bindings.push_str("    match name {\n");
-
-    // Generate match arms
+    bindings.push_str("    #[allow(clippy::match_single_binding)]\n");
+    bindings.push_str("    pub fn load_grammar_static(name: &str) -> Option<Language> {\n");
+    bindings.push_str("        match name {\n");
     for grammar in compiled_grammars {
         let const_name = grammar.name.to_uppercase();
         bindings.push_str(&format!(
-            "        \"{}\" => Some({}_LANGUAGE.into()),\n",
+            "            \"{}\" => Some({}_LANGUAGE.into()),\n",
             grammar.name, const_name
         ));
     }
+    bindings.push_str("            _ => None,\n");
+    bindings.push_str("        }\n");
+    bindings.push_str("    }\n");
+    bindings.push_str("}\n\n");
 
+    // `Grammar.symbol_name` overrides (e.g. `csharp` -> `c_sharp`) apply the
+    // same way whether a grammar is linked statically or `dlopen`ed, so both
+    // paths resolve the symbol through this one lookup.
+    bindings.push_str("#[allow(clippy::match_single_binding)]\n");
+    bindings.push_str("pub fn grammar_symbol_name(name: &str) -> Option<&'static str> {\n");
+    bindings.push_str("    match name {\n");
+    for grammar in compiled_grammars {
+        bindings.push_str(&format!(
+            "        \"{}\" => Some(\"{}\"),\n",
+            grammar.name,
+            symbol_name_for(grammar)
+        ));
+    }
     bindings.push_str("        _ => None,\n");
     bindings.push_str("    }\n");
     bindings.push_str("}\n\n");
 
+    // Unified entry point: prefer the statically-linked symbol when present,
+    // falling back to a runtime `dlopen` via `dynamic` when only
+    // `dynamic-grammar-libs` is enabled, then to a `WasmStore`-backed
+    // `.wasm` module via `wasm` when only `wasm-grammar-libs` is enabled.
+    // Compiles under any combination of these features.
+    bindings.push_str("pub fn load_grammar(name: &str) -> Option<Language> {\n");
+    bindings.push_str("    #[cfg(all(feature = \"static-grammar-libs\", not(target_arch = \"wasm32\")))]\n");
+    bindings.push_str("    if let Some(language) = static_grammars::load_grammar_static(name) {\n");
+    bindings.push_str("        return Some(language);\n");
+    bindings.push_str("    }\n\n");
+    bindings.push_str("    #[cfg(feature = \"dynamic-grammar-libs\")]\n");
+    bindings.push_str("    if let Some(symbol_name) = grammar_symbol_name(name) {\n");
+    bindings.push_str("        let runtime_dir = std::env::var(\"BREEZE_GRAMMARS_RUNTIME_DIR\")\n");
+    bindings.push_str("            .map(std::path::PathBuf::from)\n");
+    bindings.push_str("            .unwrap_or_else(|_| std::env::current_dir().unwrap());\n");
+    bindings.push_str(
+        "        if let Some(language) = crate::dynamic::load_grammar_dynamic(name, symbol_name, &runtime_dir) {\n",
+    );
+    bindings.push_str("            return Some(language);\n");
+    bindings.push_str("        }\n");
+    bindings.push_str("    }\n\n");
+    bindings.push_str("    #[cfg(feature = \"wasm-grammar-libs\")]\n");
+    bindings.push_str("    {\n");
+    bindings.push_str("        let runtime_dir = std::env::var(\"BREEZE_GRAMMARS_RUNTIME_DIR\")\n");
+    bindings.push_str("            .map(std::path::PathBuf::from)\n");
+    bindings.push_str("            .unwrap_or_else(|_| std::env::current_dir().unwrap());\n");
+    bindings.push_str("        if let Some(language) = crate::wasm::load_grammar_wasm(name, &runtime_dir) {\n");
+    bindings.push_str("            return Some(language);\n");
+    bindings.push_str("        }\n");
+    bindings.push_str("    }\n\n");
+    bindings.push_str("    #[allow(unreachable_code)]\n");
+    bindings.push_str("    None\n");
+    bindings.push_str("}\n\n");
+
     bindings.push_str("pub fn available_grammars() -> &'static [&'static str] {\n");
     bindings.push_str("    &[\n");
     for grammar in compiled_grammars {
         bindings.push_str(&format!("        \"{}\",\n", grammar.name));
     }
     bindings.push_str("    ]\n");
+    bindings.push_str("}\n\n");
+
+    // Compile-time `extension -> grammar name` lookup, so callers can parse
+    // a file by path without maintaining their own extension table.
+    bindings.push_str("#[allow(clippy::match_single_binding)]\n");
+    bindings.push_str("pub fn grammar_name_for_extension(ext: &str) -> Option<&'static str> {\n");
+    bindings.push_str("    match ext {\n");
+    for grammar in compiled_grammars {
+        for extension in &grammar.file_extensions {
+            bindings.push_str(&format!(
+                "        \"{}\" => Some(\"{}\"),\n",
+                extension, grammar.name
+            ));
+        }
+    }
+    bindings.push_str("        _ => None,\n");
+    bindings.push_str("    }\n");
+    bindings.push_str("}\n\n");
+
+    bindings.push_str("pub fn load_grammar_by_extension(ext: &str) -> Option<Language> {\n");
+    bindings.push_str("    grammar_name_for_extension(ext).and_then(load_grammar)\n");
     bindings.push_str("}\n");
 
     fs::write(bindings_path, bindings).unwrap();
 }
+
+fn symbol_name_for(grammar: &Grammar) -> String {
+    symbol_name::symbol_name_for(&grammar.name, grammar.symbol_name.as_deref())
+}
@@ -0,0 +1,107 @@
+// Timestamp-based up-to-date check for compiled grammar artifacts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Newest `SystemTime::modified()` across `paths`, or `None` if none exist.
+pub fn newest_mtime(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok()?.modified().ok())
+        .max()
+}
+
+/// Skip recompiling when no existing source in `sources` is newer than
+/// `artifact_path` (an equal mtime counts as up to date) - recompiling
+/// dozens of grammars on every `cargo build` is far too slow otherwise.
+/// Returns `false` when the artifact or every source is missing.
+pub fn artifact_is_up_to_date(sources: &[PathBuf], artifact_path: &Path) -> bool {
+    match (newest_mtime(sources), fs::metadata(artifact_path).and_then(|m| m.modified())) {
+        (Some(sources_mtime), Ok(artifact_mtime)) => sources_mtime <= artifact_mtime,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "breeze-artifact-freshness-{name}-{}-{:?}",
+                std::process::id(),
+                std::thread::current().id()
+            ));
+            fs::create_dir_all(&dir).expect("failed to create temp dir");
+            Self(dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn missing_artifact_is_not_up_to_date() {
+        let dir = TempDir::new("missing-artifact");
+        let source = dir.path().join("parser.c");
+        fs::write(&source, "").unwrap();
+
+        assert!(!artifact_is_up_to_date(
+            &[source],
+            &dir.path().join("libtree-sitter-missing.a")
+        ));
+    }
+
+    #[test]
+    fn missing_source_is_not_up_to_date() {
+        let dir = TempDir::new("missing-source");
+        let artifact = dir.path().join("libtree-sitter-missing.a");
+        fs::write(&artifact, "").unwrap();
+
+        assert!(!artifact_is_up_to_date(
+            &[dir.path().join("parser.c")],
+            &artifact
+        ));
+    }
+
+    #[test]
+    fn stale_source_is_not_up_to_date() {
+        let dir = TempDir::new("stale-source");
+        let artifact = dir.path().join("libtree-sitter-stale.a");
+        fs::write(&artifact, "").unwrap();
+
+        sleep(Duration::from_millis(20));
+
+        let source = dir.path().join("parser.c");
+        fs::write(&source, "").unwrap();
+
+        assert!(!artifact_is_up_to_date(&[source], &artifact));
+    }
+
+    #[test]
+    fn fresh_source_is_up_to_date() {
+        let dir = TempDir::new("fresh-source");
+        let source = dir.path().join("parser.c");
+        fs::write(&source, "").unwrap();
+
+        sleep(Duration::from_millis(20));
+
+        let artifact = dir.path().join("libtree-sitter-fresh.a");
+        fs::write(&artifact, "").unwrap();
+
+        assert!(artifact_is_up_to_date(&[source], &artifact));
+    }
+}
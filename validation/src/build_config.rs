@@ -0,0 +1,111 @@
+// Parsing for `languages.toml`, the user-extensible grammar manifest that
+// `build.rs` consults when deciding which grammars to build and where their
+// sources come from.
+//
+// This replaces the fixed `dist/` layout with a reproducible manifest: a
+// top-level selection filter plus a `[[grammar]]` entry per grammar, each
+// pointing at either a local checkout or a git remote/rev/subpath.
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+pub struct LanguagesConfig {
+    #[serde(rename = "use-grammars", default)]
+    pub use_grammars: Option<GrammarSelection>,
+    #[serde(rename = "grammar", default)]
+    pub grammar: Vec<GrammarEntry>,
+}
+
+// Untagged: `[use-grammars]` carries its variant's field directly (`only =
+// [...]` or `except = [...]`), not a nested `{ only = { only = [...] } }`
+// tag/content pair, so this can't be an externally tagged enum.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GrammarSelection {
+    Only { only: HashSet<String> },
+    Except { except: HashSet<String> },
+}
+
+impl GrammarSelection {
+    fn includes(&self, name: &str) -> bool {
+        match self {
+            GrammarSelection::Only { only } => only.contains(name),
+            GrammarSelection::Except { except } => !except.contains(name),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GrammarEntry {
+    pub name: String,
+    pub source: GrammarSource,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GrammarSource {
+    Local {
+        path: String,
+    },
+    Git {
+        git: String,
+        rev: String,
+        #[serde(default)]
+        subpath: Option<String>,
+    },
+}
+
+impl LanguagesConfig {
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        toml::from_str(&contents)
+            .map_err(|e| eprintln!("Validation: failed to parse {}: {e}", path.display()))
+            .ok()
+    }
+
+    /// Grammars that survive the top-level `use-grammars` filter, in
+    /// manifest order.
+    pub fn selected_grammars(&self) -> Vec<&GrammarEntry> {
+        self.grammar
+            .iter()
+            .filter(|entry| {
+                self.use_grammars
+                    .as_ref()
+                    .map(|selection| selection.includes(&entry.name))
+                    .unwrap_or(true)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_checked_in_languages_toml() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/languages.toml"));
+        let config = LanguagesConfig::load(path)
+            .unwrap_or_else(|| panic!("{} failed to parse", path.display()));
+
+        let selected = config.selected_grammars();
+        assert_eq!(
+            selected.len(),
+            5,
+            "use-grammars.only should filter languages.toml's 6 entries down to 5"
+        );
+        assert!(selected.iter().any(|entry| entry.name == "rust"));
+    }
+
+    #[test]
+    fn only_selection_is_flat_not_nested() {
+        let toml = "[use-grammars]\nonly = [\"c\"]\n";
+        let config: LanguagesConfig = toml::from_str(toml).unwrap();
+        match config.use_grammars {
+            Some(GrammarSelection::Only { only }) => assert!(only.contains("c")),
+            other => panic!("expected GrammarSelection::Only, got {other:?}"),
+        }
+    }
+}
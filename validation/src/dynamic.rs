@@ -0,0 +1,94 @@
+// Runtime grammar loading via `dlopen`, as an alternative to the grammars
+// linked statically at compile time through the generated `grammars.rs`.
+//
+// Callers that want to ship grammars separately from the binary can point
+// `load_grammar_dynamic` at a directory laid out as:
+//
+//     <runtime_dir>/grammars/<name>.<so|dylib|dll>
+//
+// Each shared library must export a `tree_sitter_<symbol>` symbol, same as
+// the statically linked grammars.
+
+use libloading::Library;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tree_sitter::Language;
+use tree_sitter_language::LanguageFn;
+
+#[cfg(target_os = "macos")]
+const GRAMMAR_LIB_EXTENSION: &str = "dylib";
+#[cfg(target_os = "windows")]
+const GRAMMAR_LIB_EXTENSION: &str = "dll";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const GRAMMAR_LIB_EXTENSION: &str = "so";
+
+// Opened libraries are kept for the lifetime of the process: the `Language`
+// values handed back to callers borrow code out of the library's mapped
+// memory, so the `Library` must never be dropped while a `Language` derived
+// from it is still alive.
+fn opened_libraries() -> &'static Mutex<HashMap<String, Library>> {
+    static LIBRARIES: OnceLock<Mutex<HashMap<String, Library>>> = OnceLock::new();
+    LIBRARIES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn grammar_library_path(name: &str, runtime_dir: &Path) -> PathBuf {
+    runtime_dir
+        .join("grammars")
+        .join(format!("{name}.{GRAMMAR_LIB_EXTENSION}"))
+}
+
+/// Load a grammar by `dlopen`-ing its shared library out of `runtime_dir`,
+/// mirroring the statically linked `load_grammar`.
+///
+/// Takes an extra `symbol_name` parameter rather than just
+/// `(name, runtime_dir)`: `Grammar.symbol_name`
+/// overrides (e.g. `csharp` -> `c_sharp`) arrived after this function, and
+/// threading the resolved symbol in here - instead of re-deriving it from
+/// `name` - is what lets both this and the statically-linked path share one
+/// `grammar_symbol_name` lookup.
+///
+/// `symbol_name` is the grammar's exported symbol *without* the
+/// `tree_sitter_` prefix - generally `name` with `-` replaced by `_`, except
+/// for grammars with an explicit `Grammar.symbol_name` override (e.g.
+/// `csharp` exports `tree_sitter_c_sharp`). Callers get this from the
+/// generated `grammar_symbol_name(name)`, the same lookup the static path
+/// uses, so a grammar with a non-default symbol resolves identically
+/// whether it's linked statically or `dlopen`ed.
+///
+/// Returns `None` if the library or the expected `tree_sitter_<symbol_name>`
+/// symbol can't be found.
+pub fn load_grammar_dynamic(name: &str, symbol_name: &str, runtime_dir: &Path) -> Option<Language> {
+    let mut libraries = opened_libraries().lock().unwrap();
+
+    if !libraries.contains_key(name) {
+        let path = grammar_library_path(name, runtime_dir);
+        let library = unsafe { Library::new(&path) }.ok()?;
+        libraries.insert(name.to_string(), library);
+    }
+
+    let library = libraries.get(name).expect("just inserted");
+    let symbol = format!("tree_sitter_{symbol_name}\0");
+    let raw_fn = *unsafe {
+        library
+            .get::<unsafe extern "C" fn() -> *const ()>(symbol.as_bytes())
+            .ok()?
+    };
+
+    let language_fn = unsafe { LanguageFn::from_raw(raw_fn) };
+    Some(language_fn.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_is_runtime_dir_slash_grammars_slash_name_dot_extension() {
+        let path = grammar_library_path("rust", Path::new("/runtime"));
+        assert_eq!(
+            path,
+            PathBuf::from(format!("/runtime/grammars/rust.{GRAMMAR_LIB_EXTENSION}"))
+        );
+    }
+}
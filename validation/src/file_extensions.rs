@@ -0,0 +1,34 @@
+/// Fallback `extension -> grammar name` pairs used when a grammar's metadata
+/// doesn't specify `file_extensions` itself (e.g. the hardcoded test set and
+/// the `languages.toml` path, which doesn't carry extension metadata).
+pub fn default_file_extensions(name: &str) -> Vec<String> {
+    let extensions: &[&str] = match name {
+        "c" => &["c", "h"],
+        "python" => &["py"],
+        "javascript" => &["js"],
+        "rust" => &["rs"],
+        "go" => &["go"],
+        "cpp" => &["cpp", "cc"],
+        "csharp" => &["cs"],
+        "ocaml" => &["ml"],
+        "typescript" => &["ts"],
+        _ => &[],
+    };
+    extensions.iter().map(|ext| ext.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_grammar_gets_its_extensions() {
+        assert_eq!(default_file_extensions("rust"), vec!["rs"]);
+        assert_eq!(default_file_extensions("cpp"), vec!["cpp", "cc"]);
+    }
+
+    #[test]
+    fn unknown_grammar_gets_no_extensions() {
+        assert!(default_file_extensions("not-a-real-grammar").is_empty());
+    }
+}
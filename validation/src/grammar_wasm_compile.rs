@@ -0,0 +1,126 @@
+// The actual emscripten invocation behind `build.rs`'s `compile_grammar_wasm`,
+// pulled out into its own shared module (via `#[path]`, same as
+// `build_config.rs`) so the round-trip test below can compile a real grammar
+// with the exact flags the build uses and load it straight back through
+// `wasm::load_grammar_wasm`/`tree_sitter::WasmStore`.
+
+use cc::Build;
+use std::path::{Path, PathBuf};
+
+/// `(parser.c, Some((scanner.c|scanner.cc, is_cpp)))` for a grammar's `src/`
+/// directory.
+pub fn find_grammar_sources(src_dir: &Path) -> (PathBuf, Option<(PathBuf, bool)>) {
+    let parser_c = src_dir.join("parser.c");
+    let scanner_cc = src_dir.join("scanner.cc");
+    let scanner_c = src_dir.join("scanner.c");
+    let scanner = if scanner_cc.exists() {
+        Some((scanner_cc, true))
+    } else if scanner_c.exists() {
+        Some((scanner_c, false))
+    } else {
+        None
+    };
+    (parser_c, scanner)
+}
+
+/// Compile a grammar's `src/parser.c` (plus `scanner.c`/`scanner.cc` if
+/// present) into a relocatable `wasm_path` side module via emscripten.
+/// `tree_sitter::WasmStore::load_language` (`ts_wasm_store_load_language` in
+/// `wasm_store.c`) unconditionally parses a `dylink.0` custom section before
+/// instantiating - that's emscripten's relocatable side-module ABI, so this
+/// has to pass `SIDE_MODULE`, matching what `tree-sitter build --wasm` emits.
+pub fn compile_grammar_wasm_module(src_dir: &Path, wasm_path: &Path) {
+    let (parser_c, scanner) = find_grammar_sources(src_dir);
+    let is_cpp = scanner.as_ref().is_some_and(|(_, is_cpp)| *is_cpp);
+
+    let mut build = Build::new();
+    build
+        .include(src_dir)
+        .cpp(is_cpp)
+        .target("wasm32-unknown-emscripten")
+        .opt_level(2);
+    let mut cmd = build.get_compiler().to_command();
+
+    cmd.arg(&parser_c);
+    if let Some((path, _)) = &scanner {
+        cmd.arg(path);
+    }
+    cmd.arg("-I").arg(src_dir);
+    cmd.arg("-s").arg("SIDE_MODULE=2");
+    cmd.arg("-o").arg(wasm_path);
+
+    let status = cmd
+        .status()
+        .unwrap_or_else(|e| panic!("failed to invoke emscripten: {e}"));
+    assert!(status.success(), "failed to build wasm module at {}", wasm_path.display());
+}
+
+#[cfg(all(test, feature = "wasm-grammar-libs"))]
+mod tests {
+    use super::*;
+    use crate::wasm::load_grammar_wasm;
+    use std::process::Command;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "breeze-wasm-roundtrip-{name}-{}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+            Self(dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // Requires `emcc` on PATH and network access to shallow-clone a real
+    // grammar; neither is available in a sandboxed/offline `cargo test` run.
+    // Run explicitly with `cargo test -- --ignored` once both are present to
+    // confirm a module built by `compile_grammar_wasm_module` actually loads
+    // through `tree_sitter::WasmStore` (catching the missing-`dylink.0`
+    // regression this test was added for).
+    #[test]
+    #[ignore = "needs `emcc` on PATH and network access to fetch a grammar checkout"]
+    fn compiled_module_round_trips_through_wasm_store() {
+        if Command::new("emcc").arg("--version").output().is_err() {
+            panic!("emcc not found on PATH");
+        }
+
+        let dir = TempDir::new("tree-sitter-c");
+        let checkout = dir.path().join("tree-sitter-c");
+        let status = Command::new("git")
+            .args([
+                "clone",
+                "--depth",
+                "1",
+                "--branch",
+                "v0.21.4",
+                "https://github.com/tree-sitter/tree-sitter-c",
+                checkout.to_str().unwrap(),
+            ])
+            .status()
+            .expect("failed to run git clone");
+        assert!(status.success(), "git clone of tree-sitter-c failed");
+
+        let wasm_path = dir.path().join("c.wasm");
+        compile_grammar_wasm_module(&checkout.join("src"), &wasm_path);
+
+        std::fs::create_dir_all(dir.path().join("grammars")).unwrap();
+        std::fs::copy(&wasm_path, dir.path().join("grammars").join("c.wasm")).unwrap();
+
+        let language =
+            load_grammar_wasm("c", dir.path()).expect("failed to load compiled c.wasm module");
+        assert!(language.node_kind_count() > 0);
+    }
+}
@@ -0,0 +1,33 @@
+/// The symbol a grammar's compiled parser exports, *without* the
+/// `tree_sitter_` prefix. Defaults to `name` with `-` replaced by `_`,
+/// except for grammars with an explicit `Grammar.symbol_name` override or a
+/// hardcoded special case (`csharp` exports `tree_sitter_c_sharp`).
+pub fn symbol_name_for(name: &str, symbol_name: Option<&str>) -> String {
+    if let Some(symbol) = symbol_name {
+        symbol.to_string()
+    } else if name == "csharp" {
+        "c_sharp".to_string()
+    } else {
+        name.replace('-', "_")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_name_with_dashes_replaced() {
+        assert_eq!(symbol_name_for("tree-sitter-ocaml", None), "tree_sitter_ocaml");
+    }
+
+    #[test]
+    fn csharp_gets_hardcoded_override() {
+        assert_eq!(symbol_name_for("csharp", None), "c_sharp");
+    }
+
+    #[test]
+    fn explicit_override_wins_over_hardcoded_default() {
+        assert_eq!(symbol_name_for("csharp", Some("sharp")), "sharp");
+    }
+}
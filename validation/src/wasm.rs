@@ -0,0 +1,57 @@
+// Loading grammars that were compiled to WebAssembly (see the `wasm32`
+// build mode in `build.rs`) through tree-sitter's `WasmStore`, for hosts
+// where the statically linked `unsafe extern "C"` symbols can't work.
+//
+// Layout matches `dynamic.rs`'s `dlopen` convention:
+// `<runtime_dir>/grammars/<name>.wasm`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tree_sitter::wasmtime::Engine;
+use tree_sitter::{Language, WasmStore};
+
+// `WasmStore` wraps a raw `*mut ffi::TSWasmStore`, so it isn't `Send`/`Sync`
+// on its own; this asserts what's actually true here - every access goes
+// through the `Mutex` below, so the store is never touched from two threads
+// at once, and nothing about it is thread-affine.
+struct ThreadSafeWasmStore(WasmStore);
+unsafe impl Send for ThreadSafeWasmStore {}
+unsafe impl Sync for ThreadSafeWasmStore {}
+
+// Like `dynamic::opened_libraries`, the store is kept for the lifetime of
+// the process: every `Language` handed back by `load_language` borrows code
+// registered in this store, so it must never be dropped while one of those
+// `Language`s is still alive.
+fn wasm_store() -> &'static Mutex<ThreadSafeWasmStore> {
+    static STORE: OnceLock<Mutex<ThreadSafeWasmStore>> = OnceLock::new();
+    STORE.get_or_init(|| {
+        let engine = Engine::default();
+        Mutex::new(ThreadSafeWasmStore(
+            WasmStore::new(engine).expect("failed to create tree-sitter WasmStore"),
+        ))
+    })
+}
+
+fn grammar_wasm_path(name: &str, runtime_dir: &Path) -> PathBuf {
+    runtime_dir.join("grammars").join(format!("{name}.wasm"))
+}
+
+/// Read `<runtime_dir>/grammars/<name>.wasm` and register it in the
+/// process-wide `WasmStore`.
+pub fn load_grammar_wasm(name: &str, runtime_dir: &Path) -> Option<Language> {
+    let bytes = fs::read(grammar_wasm_path(name, runtime_dir)).ok()?;
+    let mut store = wasm_store().lock().unwrap();
+    store.0.load_language(name, &bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_is_runtime_dir_slash_grammars_slash_name_dot_wasm() {
+        let path = grammar_wasm_path("rust", Path::new("/runtime"));
+        assert_eq!(path, PathBuf::from("/runtime/grammars/rust.wasm"));
+    }
+}